@@ -2,13 +2,16 @@
 //!
 //! For the semantics of this file, see
 //! [https://www.freedesktop.org/software/systemd/man/os-release.html](https://www.freedesktop.org/software/systemd/man/os-release.html).
+//!
+//! Enable the `serde` feature to derive `Serialize`/`Deserialize` for [`OsRelease`].
 
 #[macro_use]
 extern crate lazy_static;
 
 use std::collections::BTreeMap;
+use std::fmt;
 use std::fs::File;
-use std::io::{self, BufRead, BufReader};
+use std::io::{self, BufRead, BufReader, Write};
 use std::iter::FromIterator;
 use std::path::Path;
 
@@ -35,28 +38,133 @@ macro_rules! map_keys {
 }
 
 fn is_enclosed_with(line: &str, pattern: char) -> bool {
-    line.starts_with(pattern) && line.ends_with(pattern)
+    line.len() >= 2 && line.starts_with(pattern) && line.ends_with(pattern)
+}
+
+/// Unescapes backslash sequences the way POSIX shells do inside (or outside of) double quotes.
+///
+/// Inside double quotes, a backslash only escapes `"`, `\`, `$` and `` ` ``; before any other
+/// character it is kept as a literal backslash. Outside double quotes, a backslash escapes
+/// whatever character follows it. A backslash followed by nothing (trailing backslash) is
+/// kept literally.
+fn unescape(value: &str, in_double_quotes: bool) -> String {
+    let mut output = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some(next) if !in_double_quotes || matches!(next, '"' | '\\' | '$' | '`') => {
+                output.push(next)
+            }
+            Some(next) => {
+                output.push('\\');
+                output.push(next);
+            }
+            None => output.push('\\'),
+        }
+    }
+
+    output
 }
 
-fn parse_line(line: &str, skip: usize) -> &str {
-    let line = line[skip..].trim();
-    if is_enclosed_with(line, '"') || is_enclosed_with(line, '\'') {
-        &line[1..line.len() - 1]
+/// Parses a `KEY=value` line's value, honouring POSIX shell quoting rules.
+///
+/// Single-quoted values are taken verbatim, with no escape processing. Double-quoted values
+/// are unescaped per [`unescape`]. An unterminated quote (no matching closing quote) falls back
+/// to treating the raw remainder as an unquoted value rather than panicking.
+fn parse_line(line: &str, skip: usize) -> String {
+    let value = line[skip..].trim();
+
+    if is_enclosed_with(value, '"') {
+        unescape(&value[1..value.len() - 1], true)
+    } else if is_enclosed_with(value, '\'') {
+        value[1..value.len() - 1].to_owned()
     } else {
-        line
+        unescape(value, false)
     }
 }
 
+/// Counts the trailing backslashes on `line`. An odd count means the final backslash escapes
+/// the line's newline (a continuation); an even count means the trailing backslashes are
+/// literal, in escaped pairs.
+fn trailing_backslash_count(line: &str) -> usize {
+    line.chars().rev().take_while(|&c| c == '\\').count()
+}
+
+/// Joins physical lines that end in a backslash-newline continuation into a single logical
+/// line, per POSIX shell quoting rules.
+fn merge_continuations<I: Iterator<Item = String>>(mut lines: I) -> impl Iterator<Item = String> {
+    std::iter::from_fn(move || {
+        let mut line = lines.next()?;
+
+        while trailing_backslash_count(&line) % 2 == 1 {
+            match lines.next() {
+                Some(next) => {
+                    line.pop();
+                    line.push_str(&next);
+                }
+                // No continuation line follows; keep the trailing backslash rather than
+                // silently dropping it.
+                None => break,
+            }
+        }
+
+        Some(line)
+    })
+}
+
+fn value_needs_quoting(value: &str) -> bool {
+    value.is_empty()
+        || value.chars().any(|c| {
+            c.is_whitespace() || matches!(c, '"' | '\'' | '$' | '`' | '\\' | '#' | ';' | '&' | '|')
+        })
+}
+
+/// Quotes and escapes a value for inclusion in an os-release `KEY=value` line, per the
+/// POSIX shell quoting rules `parse_line` understands.
+fn quote_value(value: &str) -> String {
+    if !value_needs_quoting(value) {
+        return value.to_owned();
+    }
+
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        if matches!(c, '"' | '\\' | '$' | '`') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn write_field<W: Write>(out: &mut W, key: &str, value: &str) -> io::Result<()> {
+    if value.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(out, "{}={}", key, quote_value(value))
+}
+
 /// Contents of the `/etc/os-release` file, as a data structure.
 ///
 /// See
 /// [https://www.freedesktop.org/software/systemd/man/os-release.html](https://www.freedesktop.org/software/systemd/man/os-release.html)
 /// for further documentation on the fields and semantics.
 ///
-/// Quotes are removed from strings however escape sequences are not parsed.
+/// Quotes are removed from the values of known fields, and POSIX shell-style backslash escape
+/// sequences in double-quoted and unquoted values are unescaped. Values in `extra` are kept
+/// as written, quotes and backslashes included.
 ///
 /// Optional fialds which are not present default to `""`.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OsRelease {
     /// The name of this release, without the version string.
     ///
@@ -177,6 +285,93 @@ pub struct OsRelease {
 
     /// Additional keys not covered by the API.
     pub extra: BTreeMap<String, String>,
+
+    /// Which file format this information was parsed from.
+    pub source: Source,
+}
+
+/// Identifies which file format an [`OsRelease`] was parsed from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Source {
+    /// Parsed from `/etc/os-release` or `/usr/lib/os-release`.
+    OsRelease,
+    /// Parsed from `/etc/lsb-release`, via [`OsRelease::from_lsb_release`].
+    LsbRelease,
+    /// Parsed from a single-line distribution release file, such as `/etc/redhat-release`,
+    /// via [`OsRelease::from_release_file`].
+    ReleaseFile,
+}
+
+/// A parsed Common Platform Enumeration (CPE) name, as found in [`OsRelease::cpe_name`].
+///
+/// Decodes either the legacy URI binding (`cpe:/o:vendor:product:version`) or the
+/// formatted-string binding (`cpe:2.3:o:vendor:product:version:update:edition:language`).
+/// Absent trailing fields, and fields whose value is `*` or `-` (the CPE spec's "any" and "not
+/// applicable" markers), are `None`.
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cpe {
+    /// The CPE part: `o` (operating system), `a` (application), or `h` (hardware).
+    pub part: Option<String>,
+    /// The vendor or organization name.
+    pub vendor: Option<String>,
+    /// The product name.
+    pub product: Option<String>,
+    /// The product version.
+    pub version: Option<String>,
+    /// The update or service pack.
+    pub update: Option<String>,
+    /// The edition.
+    pub edition: Option<String>,
+    /// The language tag.
+    pub language: Option<String>,
+}
+
+impl Cpe {
+    /// Parses a CPE name in either the URI or formatted-string binding.
+    ///
+    /// Returns `None` if `value` does not start with a recognized `cpe:` scheme.
+    pub fn parse(value: &str) -> Option<Cpe> {
+        let rest = value
+            .strip_prefix("cpe:2.3:")
+            .or_else(|| value.strip_prefix("cpe:/"))?;
+
+        let mut fields = rest.split(':').map(cpe_field);
+
+        Some(Cpe {
+            part: fields.next().flatten(),
+            vendor: fields.next().flatten(),
+            product: fields.next().flatten(),
+            version: fields.next().flatten(),
+            update: fields.next().flatten(),
+            edition: fields.next().flatten(),
+            language: fields.next().flatten(),
+        })
+    }
+}
+
+/// Maps a raw CPE field to `None` when it is absent, `*` (any), or `-` (not applicable).
+fn cpe_field(field: &str) -> Option<String> {
+    if field.is_empty() || field == "*" || field == "-" {
+        None
+    } else {
+        Some(field.to_owned())
+    }
+}
+
+/// Finds the first dotted run of digits in `value`, such as `"18.04"` in `"18.04 LTS"`.
+fn extract_numeric_version(value: &str) -> Option<&str> {
+    let start = value.find(|c: char| c.is_ascii_digit())?;
+    let rest = &value[start..];
+    let end = rest
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(rest.len());
+
+    match rest[..end].trim_end_matches('.') {
+        "" => None,
+        token => Some(token),
+    }
 }
 
 impl Default for OsRelease {
@@ -203,37 +398,272 @@ impl Default for OsRelease {
             logo: String::new(),
 
             extra: BTreeMap::default(),
+            source: Source::OsRelease,
         }
     }
 }
 
 impl OsRelease {
-    /// Attempt to parse the contents of `/etc/os-release`.
-    /// Falls back to `/usr/lib/os-release`.
+    /// Attempt to parse the contents of `/etc/os-release`, falling back to
+    /// `/usr/lib/os-release`, then `/etc/lsb-release`, then a known distribution release file
+    /// (see [`OsRelease::from_lsb_release`] and [`OsRelease::from_release_file`]) for older or
+    /// minimal systems which lack an os-release file entirely.
     pub fn new() -> io::Result<OsRelease> {
-        let file = BufReader::new(open("/etc/os-release").or_else(|first_err| {
-            open("/usr/lib/os-release").map_err(|second_err| {
-                io::Error::new(
-                    io::ErrorKind::Other,
-                    format!("{} then {}", first_err, second_err),
-                )
-            })
-        })?);
-        Ok(OsRelease::from_iter(file.lines().flatten()))
+        let os_release_err = match open("/etc/os-release").or_else(|first_err| {
+            open("/usr/lib/os-release")
+                .map_err(|second_err| io::Error::other(format!("{} then {}", first_err, second_err)))
+        }) {
+            Ok(file) => {
+                let file = BufReader::new(file);
+                // A line that fails to decode (e.g. non-UTF-8) is skipped, not treated as EOF;
+                // the rest of the file is still parsed.
+                #[allow(clippy::lines_filter_map_ok)]
+                return Ok(OsRelease::from_iter(file.lines().flatten()));
+            }
+            Err(why) => why,
+        };
+
+        if let Ok(release) = Self::from_lsb_release() {
+            return Ok(release);
+        }
+
+        Self::from_release_file().map_err(|_| os_release_err)
     }
 
     /// Attempt to parse any `/etc/os-release`-like file.
     pub fn new_from<P: AsRef<Path>>(path: P) -> io::Result<OsRelease> {
         let file = BufReader::new(open(&path)?);
+        // A line that fails to decode (e.g. non-UTF-8) is skipped, not treated as EOF; the rest
+        // of the file is still parsed.
+        #[allow(clippy::lines_filter_map_ok)]
         Ok(OsRelease::from_iter(file.lines().flatten()))
     }
+
+    /// Attempt to parse `/etc/lsb-release`, as found on older or minimal systems which predate
+    /// `/etc/os-release`.
+    ///
+    /// Maps `DISTRIB_ID` to [`OsRelease::id`] and [`OsRelease::name`], `DISTRIB_RELEASE` to
+    /// [`OsRelease::version_id`], `DISTRIB_CODENAME` to [`OsRelease::version_codename`], and
+    /// `DISTRIB_DESCRIPTION` to [`OsRelease::pretty_name`]. Values follow the same `KEY=value`
+    /// quoting rules as `/etc/os-release`.
+    pub fn from_lsb_release() -> io::Result<OsRelease> {
+        let file = BufReader::new(open("/etc/lsb-release")?);
+        // A line that fails to decode (e.g. non-UTF-8) is skipped, not treated as EOF; the rest
+        // of the file is still parsed.
+        #[allow(clippy::lines_filter_map_ok)]
+        Ok(Self::parse_lsb_release(file.lines().flatten()))
+    }
+
+    fn parse_lsb_release<I: IntoIterator<Item = String>>(lines: I) -> OsRelease {
+        let mut id = String::new();
+        let mut version_id = String::new();
+        let mut version_codename = String::new();
+        let mut pretty_name = String::new();
+
+        for line in merge_continuations(lines.into_iter()) {
+            let line = line.trim();
+
+            map_keys!(line, {
+                "DISTRIB_ID=" => id,
+                "DISTRIB_RELEASE=" => version_id,
+                "DISTRIB_CODENAME=" => version_codename,
+                "DISTRIB_DESCRIPTION=" => pretty_name
+            });
+        }
+
+        let mut os_release = OsRelease {
+            version_id,
+            version_codename,
+            source: Source::LsbRelease,
+            ..OsRelease::default()
+        };
+
+        if !id.is_empty() {
+            os_release.id = id.to_ascii_lowercase().replace(' ', "");
+            os_release.name = id;
+        }
+
+        if !pretty_name.is_empty() {
+            os_release.pretty_name = pretty_name;
+        }
+
+        os_release
+    }
+
+    /// Attempt to parse a known single-line distribution release file, such as
+    /// `/etc/centos-release`, `/etc/redhat-release`, or `/etc/alpine-release`.
+    ///
+    /// The name and version are extracted with a simple heuristic: the text before the first
+    /// digit is the name, and the dotted numeric token starting there is the version.
+    pub fn from_release_file() -> io::Result<OsRelease> {
+        for path in RELEASE_FILES {
+            if let Ok(file) = open(path) {
+                let mut line = String::new();
+                BufReader::new(file).read_line(&mut line)?;
+                return Ok(Self::parse_release_line(&line));
+            }
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "no known distribution release file found",
+        ))
+    }
+
+    fn parse_release_line(line: &str) -> OsRelease {
+        let line = line.trim();
+        let digit_pos = line.find(|c: char| c.is_ascii_digit());
+
+        let (name, version) = match digit_pos {
+            Some(pos) => {
+                let version = line[pos..]
+                    .split(|c: char| !(c.is_ascii_digit() || c == '.'))
+                    .next()
+                    .unwrap_or("");
+                (line[..pos].trim(), version)
+            }
+            None => (line, ""),
+        };
+
+        let mut os_release = OsRelease {
+            pretty_name: line.to_owned(),
+            version_id: version.to_owned(),
+            source: Source::ReleaseFile,
+            ..OsRelease::default()
+        };
+
+        if !name.is_empty() {
+            os_release.name = name.to_owned();
+            os_release.id = name.to_ascii_lowercase().replace(' ', "");
+        }
+
+        os_release
+    }
+
+    /// The `id_like` field, split into its whitespace-separated identifiers.
+    ///
+    /// **IE:** `"rhel fedora"` becomes `vec!["rhel", "fedora"]`.
+    pub fn id_like_list(&self) -> Vec<&str> {
+        self.id_like.split_whitespace().collect()
+    }
+
+    /// Checks whether this OS is, or derives from, the given distribution `id`.
+    ///
+    /// This is true when `id` matches `self.id`, or any entry of [`OsRelease::id_like_list`].
+    /// This allows downstream code to branch distro-family logic (for example, choosing a
+    /// package manager) without re-parsing `id_like` at every call site.
+    pub fn is_like(&self, id: &str) -> bool {
+        self.id == id || self.id_like_list().contains(&id)
+    }
+
+    /// Parses [`OsRelease::cpe_name`] into its structured [`Cpe`] components.
+    ///
+    /// Returns `None` if `cpe_name` is empty or not a recognized CPE binding.
+    pub fn cpe(&self) -> Option<Cpe> {
+        Cpe::parse(&self.cpe_name)
+    }
+
+    /// Splits [`OsRelease::version_id`] into its dot-separated numeric components, ignoring a
+    /// trailing non-numeric suffix on each component (e.g. `"18.04-LTS"` yields
+    /// `["18", "04"]`).
+    ///
+    /// Returns an empty `Vec` if `version_id` is empty, as on rolling releases like Arch.
+    pub fn version_parts(&self) -> Vec<&str> {
+        self.version_id
+            .split('.')
+            .map(|part| {
+                let end = part
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(part.len());
+                &part[..end]
+            })
+            .take_while(|part| !part.is_empty())
+            .collect()
+    }
+
+    /// The major version number, parsed from the first numeric component of
+    /// [`OsRelease::version_id`].
+    ///
+    /// Returns `None` if `version_id` is empty or non-numeric, as on rolling releases like Arch.
+    pub fn version_major(&self) -> Option<u64> {
+        self.version_parts().first()?.parse().ok()
+    }
+
+    /// The minor version number, parsed from the second numeric component of
+    /// [`OsRelease::version_id`].
+    ///
+    /// Returns `None` if `version_id` has no second numeric component.
+    pub fn version_minor(&self) -> Option<u64> {
+        self.version_parts().get(1)?.parse().ok()
+    }
+
+    /// The most precise available version string.
+    ///
+    /// Prefers whichever of [`OsRelease::version_id`] and a numeric token extracted from
+    /// [`OsRelease::version`] has more dot-separated components. Returns `None` if neither
+    /// field yields a numeric version, as on rolling releases like Arch.
+    pub fn version_best(&self) -> Option<&str> {
+        let from_version = extract_numeric_version(&self.version);
+        let id_parts = self.version_parts();
+
+        if id_parts.is_empty() {
+            return from_version;
+        }
+
+        match from_version {
+            Some(from_version) if from_version.split('.').count() > id_parts.len() => {
+                Some(from_version)
+            }
+            _ => Some(self.version_id.as_str()),
+        }
+    }
+
+    /// Serializes this `OsRelease` back into `/etc/os-release` file format.
+    ///
+    /// Known fields are written as their canonical `KEY=` lines, quoting values which contain
+    /// whitespace or shell-special characters, followed by the `extra` map. Empty fields are
+    /// omitted. The result can be parsed back with [`OsRelease::from_iter`].
+    pub fn write_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        write_field(out, "NAME", &self.name)?;
+        write_field(out, "VERSION", &self.version)?;
+        write_field(out, "ID", &self.id)?;
+        write_field(out, "ID_LIKE", &self.id_like)?;
+        write_field(out, "VERSION_CODENAME", &self.version_codename)?;
+        write_field(out, "VERSION_ID", &self.version_id)?;
+        write_field(out, "PRETTY_NAME", &self.pretty_name)?;
+        write_field(out, "ANSI_COLOR", &self.ansi_color)?;
+        write_field(out, "CPE_NAME", &self.cpe_name)?;
+        write_field(out, "HOME_URL", &self.home_url)?;
+        write_field(out, "DOCUMENTATION_URL", &self.documentation_url)?;
+        write_field(out, "SUPPORT_URL", &self.support_url)?;
+        write_field(out, "BUG_REPORT_URL", &self.bug_report_url)?;
+        write_field(out, "PRIVACY_POLICY_URL", &self.privacy_policy_url)?;
+        write_field(out, "BUILD_ID", &self.build_id)?;
+        write_field(out, "VARIANT", &self.variant)?;
+        write_field(out, "VARIANT_ID", &self.variant_id)?;
+        write_field(out, "LOGO", &self.logo)?;
+
+        for (key, value) in &self.extra {
+            write_field(out, key, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for OsRelease {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = Vec::new();
+        self.write_to(&mut buffer).map_err(|_| fmt::Error)?;
+        f.write_str(&String::from_utf8_lossy(&buffer))
+    }
 }
 
 impl FromIterator<String> for OsRelease {
     fn from_iter<I: IntoIterator<Item = String>>(lines: I) -> Self {
         let mut os_release = Self::default();
 
-        for line in lines {
+        for line in merge_continuations(lines.into_iter()) {
             let line = line.trim();
 
             map_keys!(line, {
@@ -257,12 +687,10 @@ impl FromIterator<String> for OsRelease {
                 "LOGO=" => os_release.logo
             });
 
-            if let Some(pos) = line.find('=') {
-                if line.len() > pos + 1 {
-                    os_release
-                        .extra
-                        .insert(line[..pos].to_owned(), line[pos + 1..].to_owned());
-                }
+            if let Some(pos) = line.find('=').filter(|&pos| line.len() > pos + 1) {
+                os_release
+                    .extra
+                    .insert(line[..pos].to_owned(), line[pos + 1..].to_owned());
             }
         }
 
@@ -270,13 +698,19 @@ impl FromIterator<String> for OsRelease {
     }
 }
 
+/// Known single-line distribution release files, checked in order by
+/// [`OsRelease::from_release_file`].
+const RELEASE_FILES: &[&str] = &[
+    "/etc/centos-release",
+    "/etc/redhat-release",
+    "/etc/fedora-release",
+    "/etc/alpine-release",
+    "/etc/SuSE-release",
+];
+
 fn open<P: AsRef<Path>>(path: P) -> io::Result<File> {
-    File::open(&path).map_err(|why| {
-        io::Error::new(
-            io::ErrorKind::Other,
-            format!("unable to open file at {:?}: {}", path.as_ref(), why),
-        )
-    })
+    File::open(&path)
+        .map_err(|why| io::Error::other(format!("unable to open file at {:?}: {}", path.as_ref(), why)))
 }
 
 #[cfg(test)]
@@ -526,7 +960,207 @@ LOGO=I"#;
                 variant_id: "H".into(),
                 logo: "I".into(),
                 extra: BTreeMap::new(),
+                source: Source::OsRelease,
+            }
+        )
+    }
+    const ESCAPED: &str = r#"NAME="Ubuntu"
+VERSION="18.04 \"LTS\""
+ID=ubuntu
+PRETTY_NAME='It''s "Pop!_OS"'
+BUILD_ID=weird\ value
+HOME_URL="https://example.com/\$HOME"
+UNTERMINATED="no closing quote"#;
+    #[test]
+    fn os_release_escaped() {
+        let os_release = OsRelease::from_iter(ESCAPED.lines().map(|x| x.into()));
+
+        assert_eq!(os_release.version, r#"18.04 "LTS""#);
+        assert_eq!(os_release.pretty_name, "It''s \"Pop!_OS\"");
+        assert_eq!(os_release.build_id, "weird value");
+        assert_eq!(os_release.home_url, "https://example.com/$HOME");
+        assert_eq!(
+            os_release.extra.get("UNTERMINATED").map(String::as_str),
+            Some(r#""no closing quote"#)
+        );
+    }
+    const LINE_CONTINUATION: &str = "NAME=\"Pop\\\n!_OS\"\nID=pop\\\nos\n";
+    #[test]
+    fn os_release_line_continuation() {
+        let os_release =
+            OsRelease::from_iter(LINE_CONTINUATION.lines().map(|x| x.into()));
+
+        assert_eq!(os_release.name, "Pop!_OS");
+        assert_eq!(os_release.id, "popos");
+    }
+    const ODD_BACKSLASH_CONTINUATION: &str = "NAME=foo\\\\\\\nbar\n";
+    #[test]
+    fn os_release_odd_backslash_continuation() {
+        let os_release =
+            OsRelease::from_iter(ODD_BACKSLASH_CONTINUATION.lines().map(|x| x.into()));
+
+        assert_eq!(os_release.name, "foo\\bar");
+    }
+    const TRUNCATED_CONTINUATION: &str = "BUILD_ID=abc\\";
+    #[test]
+    fn os_release_truncated_continuation() {
+        let os_release =
+            OsRelease::from_iter(TRUNCATED_CONTINUATION.lines().map(|x| x.into()));
+
+        assert_eq!(os_release.build_id, "abc\\");
+    }
+    #[test]
+    fn os_release_is_like() {
+        let os_release = OsRelease::from_iter(POP.lines().map(|x| x.into()));
+
+        assert_eq!(os_release.id_like_list(), vec!["debian"]);
+        assert!(os_release.is_like("ubuntu"));
+        assert!(os_release.is_like("debian"));
+        assert!(!os_release.is_like("fedora"));
+
+        let centos = OsRelease {
+            id: "centos".into(),
+            id_like: "rhel fedora".into(),
+            ..OsRelease::default()
+        };
+        assert_eq!(centos.id_like_list(), vec!["rhel", "fedora"]);
+        assert!(centos.is_like("centos"));
+        assert!(centos.is_like("rhel"));
+        assert!(centos.is_like("fedora"));
+        assert!(!centos.is_like("debian"));
+    }
+    #[test]
+    fn os_release_display_round_trip() {
+        let os_release = OsRelease::from_iter(POP.lines().map(|x| x.into()));
+
+        let rendered = os_release.to_string();
+        let round_tripped = OsRelease::from_iter(rendered.lines().map(|x| x.into()));
+
+        assert_eq!(os_release, round_tripped);
+    }
+    #[test]
+    fn os_release_display_quoting() {
+        let os_release = OsRelease {
+            name: "Pop!_OS".into(),
+            pretty_name: "Pop!_OS 18.04 LTS".into(),
+            id: "ubuntu".into(),
+            ..OsRelease::default()
+        };
+
+        let rendered = os_release.to_string();
+
+        assert!(rendered.contains("NAME=Pop!_OS"));
+        assert!(rendered.contains(r#"PRETTY_NAME="Pop!_OS 18.04 LTS""#));
+        assert!(rendered.contains("ID=ubuntu"));
+        assert!(!rendered.contains("VERSION="));
+    }
+    const LSB_RELEASE: &str = r#"DISTRIB_ID=Ubuntu
+DISTRIB_RELEASE=18.04
+DISTRIB_CODENAME=bionic
+DISTRIB_DESCRIPTION="Ubuntu 18.04.4 LTS""#;
+    #[test]
+    fn lsb_release_parsing() {
+        let os_release = OsRelease::parse_lsb_release(LSB_RELEASE.lines().map(|x| x.into()));
+
+        assert_eq!(
+            os_release,
+            OsRelease {
+                name: "Ubuntu".into(),
+                id: "ubuntu".into(),
+                version_id: "18.04".into(),
+                version_codename: "bionic".into(),
+                pretty_name: "Ubuntu 18.04.4 LTS".into(),
+                source: Source::LsbRelease,
+                ..OsRelease::default()
             }
+        );
+        assert!(os_release.is_like("ubuntu"));
+    }
+    #[test]
+    fn release_file_parsing() {
+        let os_release = OsRelease::parse_release_line("CentOS release 6.10 (Final)\n");
+
+        assert_eq!(os_release.name, "CentOS release");
+        assert_eq!(os_release.id, "centosrelease");
+        assert_eq!(os_release.version_id, "6.10");
+        assert_eq!(os_release.source, Source::ReleaseFile);
+    }
+    #[test]
+    fn release_file_parsing_version_only() {
+        let os_release = OsRelease::parse_release_line("3.18.4\n");
+
+        assert_eq!(os_release.name, "Linux");
+        assert_eq!(os_release.version_id, "3.18.4");
+        assert_eq!(os_release.source, Source::ReleaseFile);
+    }
+    #[test]
+    fn cpe_uri_binding() {
+        let os_release = OsRelease::from_iter(FEDORA.lines().map(|x| x.into()));
+
+        assert_eq!(
+            os_release.cpe(),
+            Some(Cpe {
+                part: Some("o".into()),
+                vendor: Some("fedoraproject".into()),
+                product: Some("fedora".into()),
+                version: Some("17".into()),
+                ..Cpe::default()
+            })
         )
     }
+    #[test]
+    fn cpe_formatted_string_binding() {
+        let cpe = Cpe::parse("cpe:2.3:o:microsoft:windows_10:1607:*:*:*:*:*:*:*").unwrap();
+
+        assert_eq!(cpe.part, Some("o".into()));
+        assert_eq!(cpe.vendor, Some("microsoft".into()));
+        assert_eq!(cpe.product, Some("windows_10".into()));
+        assert_eq!(cpe.version, Some("1607".into()));
+        assert_eq!(cpe.update, None);
+        assert_eq!(cpe.edition, None);
+        assert_eq!(cpe.language, None);
+    }
+    #[test]
+    fn cpe_absent() {
+        let os_release = OsRelease::default();
+        assert_eq!(os_release.cpe(), None);
+        assert_eq!(Cpe::parse("not-a-cpe"), None);
+    }
+    #[test]
+    fn version_decomposition() {
+        let os_release = OsRelease::from_iter(UBUNTU.lines().map(|x| x.into()));
+
+        assert_eq!(os_release.version_parts(), vec!["18", "04"]);
+        assert_eq!(os_release.version_major(), Some(18));
+        assert_eq!(os_release.version_minor(), Some(4));
+        assert_eq!(os_release.version_best(), Some("18.04.4"));
+    }
+    #[test]
+    fn version_decomposition_fedora() {
+        let os_release = OsRelease::from_iter(FEDORA.lines().map(|x| x.into()));
+
+        assert_eq!(os_release.version_parts(), vec!["17"]);
+        assert_eq!(os_release.version_major(), Some(17));
+        assert_eq!(os_release.version_minor(), None);
+        assert_eq!(os_release.version_best(), Some("17"));
+    }
+    #[test]
+    fn version_decomposition_rolling_release() {
+        let os_release = OsRelease::from_iter(ARCH.lines().map(|x| x.into()));
+
+        assert!(os_release.version_parts().is_empty());
+        assert_eq!(os_release.version_major(), None);
+        assert_eq!(os_release.version_minor(), None);
+        assert_eq!(os_release.version_best(), None);
+    }
+    #[test]
+    fn version_best_non_numeric_version_id() {
+        let os_release = OsRelease {
+            version_id: "rolling".into(),
+            ..OsRelease::default()
+        };
+
+        assert!(os_release.version_parts().is_empty());
+        assert_eq!(os_release.version_best(), None);
+    }
 }